@@ -3,542 +3,304 @@
 /// Maps parameter numbers to variable names and units for different centers.
 /// Starting with ECMWF (center 98) parameters.
 
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// A linear unit-normalization recipe `cf = value * scale_factor + add_offset`
+/// taking raw GRIB values into the CF-canonical `target_units`.
+#[derive(Debug, Clone, Copy)]
+pub struct UnitConversion {
+    pub scale_factor: f64,
+    pub add_offset: f64,
+    pub target_units: &'static str,
+}
+
 #[derive(Debug, Clone)]
 pub struct Grib1Parameter {
     pub number: u8,
-    pub abbreviation: &'static str,
-    pub name: &'static str,
-    pub units: &'static str,
+    pub abbreviation: Cow<'static, str>,
+    pub name: Cow<'static, str>,
+    pub units: Cow<'static, str>,
+    /// Canonical CF `standard_name` for netCDF/Zarr export, when one applies.
+    pub cf_standard_name: Option<Cow<'static, str>>,
+    /// Recipe to normalize raw values into CF-canonical units, when needed.
+    pub unit_conversion: Option<UnitConversion>,
+    /// Lower bound for a gross-error check; defaults to negative infinity.
+    pub valid_min: f64,
+    /// Upper bound for a gross-error check; defaults to positive infinity.
+    pub valid_max: f64,
 }
 
-/// Get parameter information for a given center and parameter number
-pub fn get_parameter(center_id: u8, parameter: u8) -> Option<Grib1Parameter> {
-    match center_id {
-        98 => get_ecmwf_parameter(parameter),  // ECMWF
-        7 => get_ncep_parameter(parameter),     // NCEP
-        _ => get_wmo_standard_parameter(parameter), // WMO standard
+impl Grib1Parameter {
+    /// Build a parameter entry. Accepts both `&'static str` (built-in tables)
+    /// and owned `String` values (runtime-registered entries).
+    pub fn new(
+        number: u8,
+        abbreviation: impl Into<Cow<'static, str>>,
+        name: impl Into<Cow<'static, str>>,
+        units: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Grib1Parameter {
+            number,
+            abbreviation: abbreviation.into(),
+            name: name.into(),
+            units: units.into(),
+            cf_standard_name: None,
+            unit_conversion: None,
+            valid_min: f64::NEG_INFINITY,
+            valid_max: f64::INFINITY,
+        }
+    }
+
+    /// Attach a CF `standard_name` to this entry.
+    pub fn with_cf_standard_name(mut self, standard_name: impl Into<Cow<'static, str>>) -> Self {
+        self.cf_standard_name = Some(standard_name.into());
+        self
+    }
+
+    /// Attach a unit-normalization recipe to this entry.
+    pub fn with_unit_conversion(mut self, conversion: UnitConversion) -> Self {
+        self.unit_conversion = Some(conversion);
+        self
+    }
+
+    /// Attach a gross-error valid range `[min, max]` to this entry.
+    pub fn with_valid_range(mut self, min: f64, max: f64) -> Self {
+        self.valid_min = min;
+        self.valid_max = max;
+        self
+    }
+
+    /// Check a decoded value against the entry's valid range.
+    ///
+    /// Returns `true` when `v` lies within `[valid_min, valid_max]`; values
+    /// outside the range (and `NaN`) return `false` so a decoder can flag or
+    /// mask them during unpacking.
+    pub fn check_value(&self, v: f64) -> bool {
+        v >= self.valid_min && v <= self.valid_max
+    }
+
+    /// Map a raw GRIB value into CF-canonical units.
+    ///
+    /// Applies [`unit_conversion`](Self::unit_conversion) when present;
+    /// otherwise the value is already in CF units and is returned unchanged.
+    pub fn to_cf(&self, value: f64) -> f64 {
+        match self.unit_conversion {
+            Some(conv) => value * conv.scale_factor + conv.add_offset,
+            None => value,
+        }
+    }
+}
+
+/// Runtime-registrable store of GRIB1 parameter tables.
+///
+/// Tables are keyed by `(originating center, table version)`. A lookup that
+/// misses the requested center/version entry falls back to the built-in WMO
+/// standard table (Table 2), mirroring the add/modify/show parameter-definition
+/// workflow used by tools such as harpIO.
+#[derive(Debug, Clone, Default)]
+pub struct ParameterTableRegistry {
+    tables: HashMap<(u8, u8), HashMap<u8, Grib1Parameter>>,
+}
+
+impl ParameterTableRegistry {
+    /// An empty registry with no tables installed.
+    pub fn new() -> Self {
+        ParameterTableRegistry::default()
+    }
+
+    /// Registry pre-populated with the crate's built-in tables, each keyed
+    /// under the table version it actually corresponds to: the WMO standard
+    /// table for versions 1-3, ECMWF local table 128, NCEP (WMO) table 2 and
+    /// DWD local table 201.
+    pub fn with_builtins() -> Self {
+        let mut registry = ParameterTableRegistry::new();
+        // WMO standard Table 2 is the default for local table versions 1-3.
+        for version in 1..=DEFAULT_TABLE_VERSION {
+            registry.seed(0, version, get_wmo_standard_parameter);
+        }
+        // NCEP (center 7) follows the WMO standard table (version 2).
+        registry.seed(7, 2, get_ncep_parameter);
+        // ECMWF (center 98) local table 128.
+        registry.seed(98, 128, get_ecmwf_parameter);
+        // DWD (center 78) local table 201.
+        registry.seed(78, 201, get_dwd_table201_parameter);
+        registry
+    }
+
+    /// Populate a center/version table from one of the built-in table
+    /// functions by enumerating every parameter number it defines.
+    fn seed(&mut self, center: u8, version: u8, source: fn(u8) -> Option<Grib1Parameter>) {
+        for number in 0..=u8::MAX {
+            if let Some(param) = source(number) {
+                self.add_param(center, version, param);
+            }
+        }
     }
+
+    /// Insert (or overwrite) a parameter entry for a center/version table.
+    pub fn add_param(&mut self, center: u8, version: u8, param: Grib1Parameter) {
+        self.tables
+            .entry((center, version))
+            .or_default()
+            .insert(param.number, param);
+    }
+
+    /// Replace an entry that already exists, returning its previous value.
+    ///
+    /// Unlike [`add_param`](Self::add_param) this does not create a missing
+    /// table or entry: it returns `None` (and changes nothing) when the
+    /// center/version pair has no entry for `param.number`.
+    pub fn modify_param(
+        &mut self,
+        center: u8,
+        version: u8,
+        param: Grib1Parameter,
+    ) -> Option<Grib1Parameter> {
+        let entry = self.tables.get_mut(&(center, version))?.get_mut(&param.number)?;
+        Some(std::mem::replace(entry, param))
+    }
+
+    /// Look up a parameter.
+    ///
+    /// When the `(center, version)` pair has a table, only that table is
+    /// consulted. When it does not, the lookup falls back to the WMO standard
+    /// table — first any runtime entry registered under center `0`, then the
+    /// built-in Table 2 — so unknown centers still resolve sensibly.
+    pub fn get(&self, center: u8, version: u8, number: u8) -> Option<Grib1Parameter> {
+        if let Some(table) = self.tables.get(&(center, version)) {
+            return table.get(&number).cloned();
+        }
+        if let Some(param) = self
+            .tables
+            .get(&(0, version))
+            .and_then(|table| table.get(&number))
+        {
+            return Some(param.clone());
+        }
+        get_wmo_standard_parameter(number)
+    }
+}
+
+/// Highest WMO standard table version (Table 2) seeded by default: the
+/// built-in registry installs the WMO table under center `0` for versions 1
+/// through this one.
+pub const DEFAULT_TABLE_VERSION: u8 = 3;
+
+static ACTIVE_REGISTRY: OnceLock<RwLock<ParameterTableRegistry>> = OnceLock::new();
+
+/// The process-wide registry consulted by [`get_parameter`]. Initialised with
+/// the built-in tables on first access; downstream pipelines can lock it to
+/// add or modify entries at runtime.
+pub fn active_registry() -> &'static RwLock<ParameterTableRegistry> {
+    ACTIVE_REGISTRY.get_or_init(|| RwLock::new(ParameterTableRegistry::with_builtins()))
+}
+
+/// Get parameter information for a given center, local table version and
+/// parameter number.
+///
+/// The table version is octet 4 of the GRIB1 PDS; centers such as ECMWF (128)
+/// and DWD (201) publish whole local tables under it. Consults the active
+/// registry first so runtime overrides take precedence over the built-in
+/// tables, and falls back to the WMO standard table (versions 1-3) when the
+/// center/version pair is unknown.
+pub fn get_parameter(center_id: u8, table_version: u8, parameter: u8) -> Option<Grib1Parameter> {
+    active_registry()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(center_id, table_version, parameter)
 }
 
 /// ECMWF parameter table (center 98)
 fn get_ecmwf_parameter(parameter: u8) -> Option<Grib1Parameter> {
     let param = match parameter {
-        1 => Grib1Parameter {
-            number: 1,
-            abbreviation: "sp",
-            name: "Surface pressure",
-            units: "Pa",
-        },
-        2 => Grib1Parameter {
-            number: 2,
-            abbreviation: "prmsl",
-            name: "Pressure reduced to MSL",
-            units: "Pa",
-        },
-        11 => Grib1Parameter {
-            number: 11,
-            abbreviation: "t",
-            name: "Temperature",
-            units: "K",
-        },
-        20 => Grib1Parameter {
-            number: 20,
-            abbreviation: "vit",
-            name: "Visibility",
-            units: "m",
-        },
-        22 => Grib1Parameter {
-            number: 22,
-            abbreviation: "clmr",
-            name: "Mixing ratio",
-            units: "kg kg-1",
-        },
-        29 => Grib1Parameter {
-            number: 29,
-            abbreviation: "lvt",
-            name: "Type of low vegetation",
-            units: "~",
-        },
-        31 => Grib1Parameter {
-            number: 31,
-            abbreviation: "ci",
-            name: "Sea-ice cover",
-            units: "(0-1)",
-        },
-        32 => Grib1Parameter {
-            number: 32,
-            abbreviation: "asn",
-            name: "Snow albedo",
-            units: "(0-1)",
-        },
-        33 => Grib1Parameter {
-            number: 33,
-            abbreviation: "rsn",
-            name: "Snow density",
-            units: "kg m-3",
-        },
-        34 => Grib1Parameter {
-            number: 34,
-            abbreviation: "sstk",
-            name: "Sea surface temperature",
-            units: "K",
-        },
-        39 => Grib1Parameter {
-            number: 39,
-            abbreviation: "swvl1",
-            name: "Volumetric soil water layer 1",
-            units: "m3 m-3",
-        },
-        44 => Grib1Parameter {
-            number: 44,
-            abbreviation: "es",
-            name: "Snow evaporation",
-            units: "m of water equivalent",
-        },
-        47 => Grib1Parameter {
-            number: 47,
-            abbreviation: "dsrp",
-            name: "Direct solar radiation",
-            units: "W m-2 s",
-        },
-        49 => Grib1Parameter {
-            number: 49,
-            abbreviation: "10fg",
-            name: "10 metre wind gust",
-            units: "m s-1",
-        },
-        50 => Grib1Parameter {
-            number: 50,
-            abbreviation: "lspf",
-            name: "Large-scale precipitation fraction",
-            units: "s",
-        },
-        51 => Grib1Parameter {
-            number: 51,
-            abbreviation: "q",
-            name: "Specific humidity",
-            units: "kg kg-1",
-        },
-        52 => Grib1Parameter {
-            number: 52,
-            abbreviation: "r",
-            name: "Relative humidity",
-            units: "%",
-        },
-        53 => Grib1Parameter {
-            number: 53,
-            abbreviation: "q",
-            name: "Humidity mixing ratio",
-            units: "kg kg-1",
-        },
-        54 => Grib1Parameter {
-            number: 54,
-            abbreviation: "pwat",
-            name: "Precipitable water",
-            units: "kg m-2",
-        },
-        59 => Grib1Parameter {
-            number: 59,
-            abbreviation: "prate",
-            name: "Precipitation rate",
-            units: "kg m-2 s-1",
-        },
-        61 => Grib1Parameter {
-            number: 61,
-            abbreviation: "tp",
-            name: "Total precipitation",
-            units: "m",
-        },
-        66 => Grib1Parameter {
-            number: 66,
-            abbreviation: "lsff",
-            name: "Lake shape factor",
-            units: "dimensionless",
-        },
-        67 => Grib1Parameter {
-            number: 67,
-            abbreviation: "lmlt",
-            name: "Lake mix-layer temperature",
-            units: "K",
-        },
-        71 => Grib1Parameter {
-            number: 71,
-            abbreviation: "tcc",
-            name: "Total cloud cover",
-            units: "%",
-        },
-        78 => Grib1Parameter {
-            number: 78,
-            abbreviation: "tclw",
-            name: "Total column cloud liquid water",
-            units: "kg m-2",
-        },
-        79 => Grib1Parameter {
-            number: 79,
-            abbreviation: "tciw",
-            name: "Total column cloud ice water",
-            units: "kg m-2",
-        },
-        89 => Grib1Parameter {
-            number: 89,
-            abbreviation: "sunsd",
-            name: "Sunshine duration",
-            units: "s",
-        },
-        121 => Grib1Parameter {
-            number: 121,
-            abbreviation: "mx2t",
-            name: "Maximum temperature at 2 metres",
-            units: "K",
-        },
-        122 => Grib1Parameter {
-            number: 122,
-            abbreviation: "mn2t",
-            name: "Minimum temperature at 2 metres",
-            units: "K",
-        },
-        123 => Grib1Parameter {
-            number: 123,
-            abbreviation: "10fg",
-            name: "10 metre wind gust",
-            units: "m s-1",
-        },
-        124 => Grib1Parameter {
-            number: 124,
-            abbreviation: "emis",
-            name: "Surface emissivity",
-            units: "dimensionless",
-        },
-        125 => Grib1Parameter {
-            number: 125,
-            abbreviation: "veg",
-            name: "Vegetation fraction",
-            units: "(0-1)",
-        },
-        126 => Grib1Parameter {
-            number: 126,
-            abbreviation: "sltyp",
-            name: "Soil type",
-            units: "dimensionless",
-        },
-        127 => Grib1Parameter {
-            number: 127,
-            abbreviation: "cape",
-            name: "Convective available potential energy",
-            units: "J kg-1",
-        },
-        128 => Grib1Parameter {
-            number: 128,
-            abbreviation: "cin",
-            name: "Convective inhibition",
-            units: "J kg-1",
-        },
-        129 => Grib1Parameter {
-            number: 129,
-            abbreviation: "z",
-            name: "Geopotential",
-            units: "m2 s-2",
-        },
-        130 => Grib1Parameter {
-            number: 130,
-            abbreviation: "t",
-            name: "Temperature",
-            units: "K",
-        },
-        131 => Grib1Parameter {
-            number: 131,
-            abbreviation: "u",
-            name: "U component of wind",
-            units: "m s-1",
-        },
-        132 => Grib1Parameter {
-            number: 132,
-            abbreviation: "v",
-            name: "V component of wind",
-            units: "m s-1",
-        },
-        133 => Grib1Parameter {
-            number: 133,
-            abbreviation: "q",
-            name: "Specific humidity",
-            units: "kg kg-1",
-        },
-        134 => Grib1Parameter {
-            number: 134,
-            abbreviation: "sp",
-            name: "Surface pressure",
-            units: "Pa",
-        },
-        135 => Grib1Parameter {
-            number: 135,
-            abbreviation: "w",
-            name: "Vertical velocity",
-            units: "Pa s-1",
-        },
-        136 => Grib1Parameter {
-            number: 136,
-            abbreviation: "tcw",
-            name: "Total column water",
-            units: "kg m-2",
-        },
-        137 => Grib1Parameter {
-            number: 137,
-            abbreviation: "tcwv",
-            name: "Total column water vapour",
-            units: "kg m-2",
-        },
-        139 => Grib1Parameter {
-            number: 139,
-            abbreviation: "stl1",
-            name: "Soil temperature level 1",
-            units: "K",
-        },
-        141 => Grib1Parameter {
-            number: 141,
-            abbreviation: "sd",
-            name: "Snow depth",
-            units: "m of water equivalent",
-        },
-        143 => Grib1Parameter {
-            number: 143,
-            abbreviation: "cp",
-            name: "Convective precipitation",
-            units: "m",
-        },
-        144 => Grib1Parameter {
-            number: 144,
-            abbreviation: "sf",
-            name: "Snowfall",
-            units: "m of water equivalent",
-        },
-        148 => Grib1Parameter {
-            number: 148,
-            abbreviation: "chnk",
-            name: "Charnock",
-            units: "dimensionless",
-        },
-        151 => Grib1Parameter {
-            number: 151,
-            abbreviation: "prmsl",
-            name: "Pressure reduced to MSL",
-            units: "Pa",
-        },
-        157 => Grib1Parameter {
-            number: 157,
-            abbreviation: "r",
-            name: "Relative humidity",
-            units: "%",
-        },
-        159 => Grib1Parameter {
-            number: 159,
-            abbreviation: "blh",
-            name: "Boundary layer height",
-            units: "m",
-        },
-        164 => Grib1Parameter {
-            number: 164,
-            abbreviation: "tcc",
-            name: "Total cloud cover",
-            units: "(0-1)",
-        },
-        165 => Grib1Parameter {
-            number: 165,
-            abbreviation: "u10",
-            name: "10 metre U wind component",
-            units: "m s-1",
-        },
-        166 => Grib1Parameter {
-            number: 166,
-            abbreviation: "v10",
-            name: "10 metre V wind component",
-            units: "m s-1",
-        },
-        167 => Grib1Parameter {
-            number: 167,
-            abbreviation: "t2m",
-            name: "2 metre temperature",
-            units: "K",
-        },
-        168 => Grib1Parameter {
-            number: 168,
-            abbreviation: "d2m",
-            name: "2 metre dewpoint temperature",
-            units: "K",
-        },
-        169 => Grib1Parameter {
-            number: 169,
-            abbreviation: "ssrd",
-            name: "Surface solar radiation downwards",
-            units: "J m-2",
-        },
-        179 => Grib1Parameter {
-            number: 179,
-            abbreviation: "ttr",
-            name: "Top net thermal radiation",
-            units: "J m-2",
-        },
-        186 => Grib1Parameter {
-            number: 186,
-            abbreviation: "lcc",
-            name: "Low cloud cover",
-            units: "(0-1)",
-        },
-        187 => Grib1Parameter {
-            number: 187,
-            abbreviation: "mcc",
-            name: "Medium cloud cover",
-            units: "(0-1)",
-        },
-        188 => Grib1Parameter {
-            number: 188,
-            abbreviation: "hcc",
-            name: "High cloud cover",
-            units: "(0-1)",
-        },
-        213 => Grib1Parameter {
-            number: 213,
-            abbreviation: "vimd",
-            name: "Vertically integrated moisture divergence",
-            units: "kg m-2",
-        },
-        217 => Grib1Parameter {
-            number: 217,
-            abbreviation: "sdwe",
-            name: "Standard deviation wave height",
-            units: "m",
-        },
-        218 => Grib1Parameter {
-            number: 218,
-            abbreviation: "mpww",
-            name: "Mean wave period based on second moment",
-            units: "s",
-        },
-        219 => Grib1Parameter {
-            number: 219,
-            abbreviation: "p1ww",
-            name: "Mean wave period based on first moment",
-            units: "s",
-        },
-        220 => Grib1Parameter {
-            number: 220,
-            abbreviation: "mzww",
-            name: "Mean zero-crossing wave period",
-            units: "s",
-        },
-        221 => Grib1Parameter {
-            number: 221,
-            abbreviation: "ipww",
-            name: "Mean period of wind waves",
-            units: "s",
-        },
-        226 => Grib1Parameter {
-            number: 226,
-            abbreviation: "10ws",
-            name: "10 metre wind speed",
-            units: "m s-1",
-        },
-        228 => Grib1Parameter {
-            number: 228,
-            abbreviation: "tp",
-            name: "Total precipitation",
-            units: "m",
-        },
-        229 => Grib1Parameter {
-            number: 229,
-            abbreviation: "iews",
-            name: "Instantaneous eastward turbulent surface stress",
-            units: "N m-2",
-        },
-        230 => Grib1Parameter {
-            number: 230,
-            abbreviation: "inss",
-            name: "Instantaneous northward turbulent surface stress",
-            units: "N m-2",
-        },
-        231 => Grib1Parameter {
-            number: 231,
-            abbreviation: "ishf",
-            name: "Instantaneous surface heat flux",
-            units: "W m-2",
-        },
-        232 => Grib1Parameter {
-            number: 232,
-            abbreviation: "ie",
-            name: "Instantaneous moisture flux",
-            units: "kg m-2 s-1",
-        },
-        234 => Grib1Parameter {
-            number: 234,
-            abbreviation: "lsrh",
-            name: "Logarithm of surface roughness length for heat",
-            units: "dimensionless",
-        },
-        235 => Grib1Parameter {
-            number: 235,
-            abbreviation: "skt",
-            name: "Skin temperature",
-            units: "K",
-        },
-        236 => Grib1Parameter {
-            number: 236,
-            abbreviation: "stl4",
-            name: "Soil temperature level 4",
-            units: "K",
-        },
-        237 => Grib1Parameter {
-            number: 237,
-            abbreviation: "swvl4",
-            name: "Volumetric soil water layer 4",
-            units: "m3 m-3",
-        },
-        238 => Grib1Parameter {
-            number: 238,
-            abbreviation: "tsn",
-            name: "Temperature of snow layer",
-            units: "K",
-        },
-        239 => Grib1Parameter {
-            number: 239,
-            abbreviation: "csf",
-            name: "Convective snowfall",
-            units: "m of water equivalent",
-        },
-        240 => Grib1Parameter {
-            number: 240,
-            abbreviation: "lsf",
-            name: "Large-scale snowfall",
-            units: "m of water equivalent",
-        },
-        241 => Grib1Parameter {
-            number: 241,
-            abbreviation: "acf",
-            name: "Accumulated cloud fraction tendency",
-            units: "(-1 to 1)",
-        },
-        243 => Grib1Parameter {
-            number: 243,
-            abbreviation: "fal",
-            name: "Forecast albedo",
-            units: "(0-1)",
-        },
-        244 => Grib1Parameter {
-            number: 244,
-            abbreviation: "fsr",
-            name: "Forecast surface roughness",
-            units: "m",
-        },
-        246 => Grib1Parameter {
-            number: 246,
-            abbreviation: "clwc",
-            name: "Cloud liquid water content",
-            units: "kg kg-1",
-        },
-        247 => Grib1Parameter {
-            number: 247,
-            abbreviation: "ciwc",
-            name: "Cloud ice water content",
-            units: "kg kg-1",
-        },
+        1 => Grib1Parameter::new(1, "sp", "Surface pressure", "Pa").with_cf_standard_name("surface_air_pressure"),
+        2 => Grib1Parameter::new(2, "prmsl", "Pressure reduced to MSL", "Pa").with_cf_standard_name("air_pressure_at_mean_sea_level"),
+        11 => Grib1Parameter::new(11, "t", "Temperature", "K").with_cf_standard_name("air_temperature"),
+        20 => Grib1Parameter::new(20, "vit", "Visibility", "m"),
+        22 => Grib1Parameter::new(22, "clmr", "Mixing ratio", "kg kg-1"),
+        29 => Grib1Parameter::new(29, "lvt", "Type of low vegetation", "~"),
+        31 => Grib1Parameter::new(31, "ci", "Sea-ice cover", "(0-1)").with_cf_standard_name("sea_ice_area_fraction").with_valid_range(0.0, 1.0),
+        32 => Grib1Parameter::new(32, "asn", "Snow albedo", "(0-1)").with_valid_range(0.0, 1.0),
+        33 => Grib1Parameter::new(33, "rsn", "Snow density", "kg m-3"),
+        34 => Grib1Parameter::new(34, "sstk", "Sea surface temperature", "K").with_cf_standard_name("sea_surface_temperature"),
+        39 => Grib1Parameter::new(39, "swvl1", "Volumetric soil water layer 1", "m3 m-3"),
+        44 => Grib1Parameter::new(44, "es", "Snow evaporation", "m of water equivalent"),
+        47 => Grib1Parameter::new(47, "dsrp", "Direct solar radiation", "W m-2 s"),
+        49 => Grib1Parameter::new(49, "10fg", "10 metre wind gust", "m s-1"),
+        50 => Grib1Parameter::new(50, "lspf", "Large-scale precipitation fraction", "s"),
+        51 => Grib1Parameter::new(51, "q", "Specific humidity", "kg kg-1").with_cf_standard_name("specific_humidity").with_valid_range(0.0, f64::INFINITY),
+        52 => Grib1Parameter::new(52, "r", "Relative humidity", "%").with_cf_standard_name("relative_humidity").with_valid_range(0.0, 120.0),
+        53 => Grib1Parameter::new(53, "q", "Humidity mixing ratio", "kg kg-1"),
+        54 => Grib1Parameter::new(54, "pwat", "Precipitable water", "kg m-2"),
+        59 => Grib1Parameter::new(59, "prate", "Precipitation rate", "kg m-2 s-1"),
+        61 => Grib1Parameter::new(61, "tp", "Total precipitation", "m").with_cf_standard_name("precipitation_amount").with_unit_conversion(UnitConversion { scale_factor: 1000.0, add_offset: 0.0, target_units: "kg m-2" }).with_valid_range(0.0, f64::INFINITY),
+        66 => Grib1Parameter::new(66, "lsff", "Lake shape factor", "dimensionless"),
+        67 => Grib1Parameter::new(67, "lmlt", "Lake mix-layer temperature", "K"),
+        71 => Grib1Parameter::new(71, "tcc", "Total cloud cover", "%").with_cf_standard_name("cloud_area_fraction").with_valid_range(0.0, 100.0),
+        78 => Grib1Parameter::new(78, "tclw", "Total column cloud liquid water", "kg m-2"),
+        79 => Grib1Parameter::new(79, "tciw", "Total column cloud ice water", "kg m-2"),
+        89 => Grib1Parameter::new(89, "sunsd", "Sunshine duration", "s"),
+        121 => Grib1Parameter::new(121, "mx2t", "Maximum temperature at 2 metres", "K"),
+        122 => Grib1Parameter::new(122, "mn2t", "Minimum temperature at 2 metres", "K"),
+        123 => Grib1Parameter::new(123, "10fg", "10 metre wind gust", "m s-1"),
+        124 => Grib1Parameter::new(124, "emis", "Surface emissivity", "dimensionless"),
+        125 => Grib1Parameter::new(125, "veg", "Vegetation fraction", "(0-1)").with_valid_range(0.0, 1.0),
+        126 => Grib1Parameter::new(126, "sltyp", "Soil type", "dimensionless"),
+        127 => Grib1Parameter::new(127, "cape", "Convective available potential energy", "J kg-1"),
+        128 => Grib1Parameter::new(128, "cin", "Convective inhibition", "J kg-1"),
+        129 => Grib1Parameter::new(129, "z", "Geopotential", "m2 s-2").with_cf_standard_name("geopotential"),
+        130 => Grib1Parameter::new(130, "t", "Temperature", "K").with_cf_standard_name("air_temperature"),
+        131 => Grib1Parameter::new(131, "u", "U component of wind", "m s-1").with_cf_standard_name("eastward_wind"),
+        132 => Grib1Parameter::new(132, "v", "V component of wind", "m s-1").with_cf_standard_name("northward_wind"),
+        133 => Grib1Parameter::new(133, "q", "Specific humidity", "kg kg-1"),
+        134 => Grib1Parameter::new(134, "sp", "Surface pressure", "Pa").with_cf_standard_name("surface_air_pressure"),
+        135 => Grib1Parameter::new(135, "w", "Vertical velocity", "Pa s-1"),
+        136 => Grib1Parameter::new(136, "tcw", "Total column water", "kg m-2"),
+        137 => Grib1Parameter::new(137, "tcwv", "Total column water vapour", "kg m-2"),
+        139 => Grib1Parameter::new(139, "stl1", "Soil temperature level 1", "K"),
+        141 => Grib1Parameter::new(141, "sd", "Snow depth", "m of water equivalent"),
+        143 => Grib1Parameter::new(143, "cp", "Convective precipitation", "m"),
+        144 => Grib1Parameter::new(144, "sf", "Snowfall", "m of water equivalent"),
+        148 => Grib1Parameter::new(148, "chnk", "Charnock", "dimensionless"),
+        151 => Grib1Parameter::new(151, "prmsl", "Pressure reduced to MSL", "Pa"),
+        157 => Grib1Parameter::new(157, "r", "Relative humidity", "%").with_valid_range(0.0, 120.0),
+        159 => Grib1Parameter::new(159, "blh", "Boundary layer height", "m"),
+        164 => Grib1Parameter::new(164, "tcc", "Total cloud cover", "(0-1)").with_valid_range(0.0, 1.0),
+        165 => Grib1Parameter::new(165, "u10", "10 metre U wind component", "m s-1").with_cf_standard_name("eastward_wind"),
+        166 => Grib1Parameter::new(166, "v10", "10 metre V wind component", "m s-1").with_cf_standard_name("northward_wind"),
+        167 => Grib1Parameter::new(167, "t2m", "2 metre temperature", "K").with_cf_standard_name("air_temperature"),
+        168 => Grib1Parameter::new(168, "d2m", "2 metre dewpoint temperature", "K").with_cf_standard_name("dew_point_temperature"),
+        169 => Grib1Parameter::new(169, "ssrd", "Surface solar radiation downwards", "J m-2"),
+        179 => Grib1Parameter::new(179, "ttr", "Top net thermal radiation", "J m-2"),
+        186 => Grib1Parameter::new(186, "lcc", "Low cloud cover", "(0-1)").with_valid_range(0.0, 1.0),
+        187 => Grib1Parameter::new(187, "mcc", "Medium cloud cover", "(0-1)").with_valid_range(0.0, 1.0),
+        188 => Grib1Parameter::new(188, "hcc", "High cloud cover", "(0-1)").with_valid_range(0.0, 1.0),
+        213 => Grib1Parameter::new(213, "vimd", "Vertically integrated moisture divergence", "kg m-2"),
+        217 => Grib1Parameter::new(217, "sdwe", "Standard deviation wave height", "m"),
+        218 => Grib1Parameter::new(218, "mpww", "Mean wave period based on second moment", "s"),
+        219 => Grib1Parameter::new(219, "p1ww", "Mean wave period based on first moment", "s"),
+        220 => Grib1Parameter::new(220, "mzww", "Mean zero-crossing wave period", "s"),
+        221 => Grib1Parameter::new(221, "ipww", "Mean period of wind waves", "s"),
+        226 => Grib1Parameter::new(226, "10ws", "10 metre wind speed", "m s-1"),
+        228 => Grib1Parameter::new(228, "tp", "Total precipitation", "m").with_cf_standard_name("precipitation_amount").with_unit_conversion(UnitConversion { scale_factor: 1000.0, add_offset: 0.0, target_units: "kg m-2" }).with_valid_range(0.0, f64::INFINITY),
+        229 => Grib1Parameter::new(229, "iews", "Instantaneous eastward turbulent surface stress", "N m-2"),
+        230 => Grib1Parameter::new(230, "inss", "Instantaneous northward turbulent surface stress", "N m-2"),
+        231 => Grib1Parameter::new(231, "ishf", "Instantaneous surface heat flux", "W m-2"),
+        232 => Grib1Parameter::new(232, "ie", "Instantaneous moisture flux", "kg m-2 s-1"),
+        234 => Grib1Parameter::new(234, "lsrh", "Logarithm of surface roughness length for heat", "dimensionless"),
+        235 => Grib1Parameter::new(235, "skt", "Skin temperature", "K").with_cf_standard_name("surface_temperature"),
+        236 => Grib1Parameter::new(236, "stl4", "Soil temperature level 4", "K"),
+        237 => Grib1Parameter::new(237, "swvl4", "Volumetric soil water layer 4", "m3 m-3"),
+        238 => Grib1Parameter::new(238, "tsn", "Temperature of snow layer", "K"),
+        239 => Grib1Parameter::new(239, "csf", "Convective snowfall", "m of water equivalent"),
+        240 => Grib1Parameter::new(240, "lsf", "Large-scale snowfall", "m of water equivalent"),
+        241 => Grib1Parameter::new(241, "acf", "Accumulated cloud fraction tendency", "(-1 to 1)"),
+        243 => Grib1Parameter::new(243, "fal", "Forecast albedo", "(0-1)").with_valid_range(0.0, 1.0),
+        244 => Grib1Parameter::new(244, "fsr", "Forecast surface roughness", "m"),
+        246 => Grib1Parameter::new(246, "clwc", "Cloud liquid water content", "kg kg-1"),
+        247 => Grib1Parameter::new(247, "ciwc", "Cloud ice water content", "kg kg-1"),
         _ => return None,
     };
 
@@ -550,75 +312,188 @@ fn get_ncep_parameter(parameter: u8) -> Option<Grib1Parameter> {
     get_wmo_standard_parameter(parameter)
 }
 
+/// DWD (center 78) local parameter table 201 - radiation fluxes, soil
+/// variables and radiative heating rates.
+fn get_dwd_table201_parameter(parameter: u8) -> Option<Grib1Parameter> {
+    let param = match parameter {
+        1 => Grib1Parameter::new(1, "swdir", "Downward shortwave radiant flux density", "W m-2"),
+        2 => Grib1Parameter::new(2, "swdifd", "Downward diffuse shortwave radiant flux density", "W m-2"),
+        3 => Grib1Parameter::new(3, "swdifu", "Upward diffuse shortwave radiant flux density", "W m-2"),
+        4 => Grib1Parameter::new(4, "lwd", "Downward longwave radiant flux density", "W m-2"),
+        5 => Grib1Parameter::new(5, "lwu", "Upward longwave radiant flux density", "W m-2"),
+        11 => Grib1Parameter::new(11, "sohr", "Shortwave radiative heating rate", "K s-1"),
+        12 => Grib1Parameter::new(12, "thhr", "Longwave radiative heating rate", "K s-1"),
+        197 => Grib1Parameter::new(197, "wso", "Soil moisture content", "kg m-2"),
+        198 => Grib1Parameter::new(198, "wsoice", "Soil ice content", "kg m-2"),
+        199 => Grib1Parameter::new(199, "tso", "Soil temperature", "K"),
+        _ => return None,
+    };
+
+    Some(param)
+}
+
 /// WMO standard parameter table (Table 2)
 fn get_wmo_standard_parameter(parameter: u8) -> Option<Grib1Parameter> {
     let param = match parameter {
-        1 => Grib1Parameter {
-            number: 1,
-            abbreviation: "pres",
-            name: "Pressure",
-            units: "Pa",
-        },
-        2 => Grib1Parameter {
-            number: 2,
-            abbreviation: "prmsl",
-            name: "Pressure reduced to MSL",
-            units: "Pa",
-        },
-        7 => Grib1Parameter {
-            number: 7,
-            abbreviation: "gh",
-            name: "Geopotential height",
-            units: "gpm",
-        },
-        11 => Grib1Parameter {
-            number: 11,
-            abbreviation: "t",
-            name: "Temperature",
-            units: "K",
-        },
-        33 => Grib1Parameter {
-            number: 33,
-            abbreviation: "u",
-            name: "U-component of wind",
-            units: "m s-1",
-        },
-        34 => Grib1Parameter {
-            number: 34,
-            abbreviation: "v",
-            name: "V-component of wind",
-            units: "m s-1",
-        },
-        39 => Grib1Parameter {
-            number: 39,
-            abbreviation: "w",
-            name: "Vertical velocity",
-            units: "Pa s-1",
-        },
-        51 => Grib1Parameter {
-            number: 51,
-            abbreviation: "q",
-            name: "Specific humidity",
-            units: "kg kg-1",
-        },
-        52 => Grib1Parameter {
-            number: 52,
-            abbreviation: "r",
-            name: "Relative humidity",
-            units: "%",
-        },
-        61 => Grib1Parameter {
-            number: 61,
-            abbreviation: "tp",
-            name: "Total precipitation",
-            units: "kg m-2",
-        },
+        1 => Grib1Parameter::new(1, "pres", "Pressure", "Pa"),
+        2 => Grib1Parameter::new(2, "prmsl", "Pressure reduced to MSL", "Pa").with_cf_standard_name("air_pressure_at_mean_sea_level"),
+        7 => Grib1Parameter::new(7, "gh", "Geopotential height", "gpm").with_cf_standard_name("geopotential_height"),
+        11 => Grib1Parameter::new(11, "t", "Temperature", "K").with_cf_standard_name("air_temperature"),
+        33 => Grib1Parameter::new(33, "u", "U-component of wind", "m s-1").with_cf_standard_name("eastward_wind"),
+        34 => Grib1Parameter::new(34, "v", "V-component of wind", "m s-1").with_cf_standard_name("northward_wind"),
+        39 => Grib1Parameter::new(39, "w", "Vertical velocity", "Pa s-1").with_cf_standard_name("lagrangian_tendency_of_air_pressure"),
+        51 => Grib1Parameter::new(51, "q", "Specific humidity", "kg kg-1").with_cf_standard_name("specific_humidity").with_valid_range(0.0, f64::INFINITY),
+        52 => Grib1Parameter::new(52, "r", "Relative humidity", "%").with_cf_standard_name("relative_humidity").with_valid_range(0.0, 120.0),
+        61 => Grib1Parameter::new(61, "tp", "Total precipitation", "kg m-2").with_cf_standard_name("precipitation_amount").with_valid_range(0.0, f64::INFINITY),
         _ => return None,
     };
 
     Some(param)
 }
 
+/// A predefined GRIB1 catalog grid resolved from a grid-catalog number.
+///
+/// GRIB1 GDS octet 7 may cite a catalog number instead of fully specifying the
+/// geometry; [`get_predefined_grid`] expands the number into the regular
+/// lat/lon geometry so a decoder can reconstruct coordinates. Ranges are
+/// `(first, last)` in degrees and follow the catalog scan order, so a
+/// north-to-south grid has `lat_range.0 > lat_range.1`.
+#[derive(Debug, Clone, Copy)]
+pub struct PredefinedGrid {
+    pub dlon: f64,
+    pub dlat: f64,
+    pub lon_range: (f64, f64),
+    pub lat_range: (f64, f64),
+    pub nx: usize,
+    pub ny: usize,
+    pub npoints: usize,
+    /// The last latitude row is the north pole collapsed to a single point.
+    pub north_pole_row: bool,
+    /// The last latitude row is the south pole collapsed to a single point.
+    pub south_pole_row: bool,
+}
+
+/// Resolve a GRIB1 predefined grid-catalog number to its full geometry.
+///
+/// Covers the standard WMO international exchange grids (21-26): quarter-sphere
+/// (21-24) and hemispheric (25-26) lat/lon grids whose pole latitude is carried
+/// as a single point, which the point count reflects.
+pub fn get_predefined_grid(grid_number: u8) -> Option<PredefinedGrid> {
+    let grid = match grid_number {
+        21 => PredefinedGrid {
+            dlon: 5.0,
+            dlat: 2.5,
+            lon_range: (0.0, 180.0),
+            lat_range: (0.0, 90.0),
+            nx: 37,
+            ny: 37,
+            npoints: 1333,
+            north_pole_row: true,
+            south_pole_row: false,
+        },
+        22 => PredefinedGrid {
+            dlon: 5.0,
+            dlat: 2.5,
+            lon_range: (180.0, 360.0),
+            lat_range: (0.0, 90.0),
+            nx: 37,
+            ny: 37,
+            npoints: 1333,
+            north_pole_row: true,
+            south_pole_row: false,
+        },
+        23 => PredefinedGrid {
+            dlon: 5.0,
+            dlat: 2.5,
+            lon_range: (0.0, 180.0),
+            lat_range: (0.0, -90.0),
+            nx: 37,
+            ny: 37,
+            npoints: 1333,
+            north_pole_row: false,
+            south_pole_row: true,
+        },
+        24 => PredefinedGrid {
+            dlon: 5.0,
+            dlat: 2.5,
+            lon_range: (180.0, 360.0),
+            lat_range: (0.0, -90.0),
+            nx: 37,
+            ny: 37,
+            npoints: 1333,
+            north_pole_row: false,
+            south_pole_row: true,
+        },
+        25 => PredefinedGrid {
+            dlon: 5.0,
+            dlat: 5.0,
+            lon_range: (0.0, 355.0),
+            lat_range: (0.0, 90.0),
+            nx: 72,
+            ny: 19,
+            npoints: 1297,
+            north_pole_row: true,
+            south_pole_row: false,
+        },
+        26 => PredefinedGrid {
+            dlon: 5.0,
+            dlat: 5.0,
+            lon_range: (0.0, 355.0),
+            lat_range: (0.0, -90.0),
+            nx: 72,
+            ny: 19,
+            npoints: 1297,
+            north_pole_row: false,
+            south_pole_row: true,
+        },
+        _ => return None,
+    };
+
+    Some(grid)
+}
+
+/// Resolve a WMO originating-center identifier to its human-readable name.
+///
+/// Covers the common entries from WMO Common Code Table C-1; unrecognised
+/// identifiers resolve to `"unknown"` so callers always have a label to emit.
+pub fn get_center_name(center_id: u8) -> &'static str {
+    match center_id {
+        7 => "US National Weather Service - NCEP",
+        8 => "US National Weather Service - NWS Telecommunications Gateway",
+        34 => "Japanese Meteorological Agency - Tokyo",
+        52 => "US National Hurricane Center, Miami",
+        54 => "Canadian Meteorological Service - Montreal",
+        58 => "US Fleet Numerical Meteorology and Oceanography Center",
+        74 => "UK Meteorological Office - Exeter",
+        78 => "Deutscher Wetterdienst - Offenbach",
+        85 => "Meteo-France - Toulouse",
+        97 => "European Space Agency",
+        98 => "European Centre for Medium-Range Weather Forecasts",
+        99 => "Royal Netherlands Meteorological Institute - De Bilt",
+        _ => "unknown",
+    }
+}
+
+/// Resolve a subcenter identifier to its name within an originating center.
+///
+/// Only the common NCEP (center 7) subcenter splits are tabulated; any other
+/// center/subcenter pair resolves to `"unknown"`.
+pub fn get_subcenter_name(center_id: u8, subcenter_id: u8) -> &'static str {
+    match (center_id, subcenter_id) {
+        (7, 1) => "NCEP Re-Analysis Project",
+        (7, 2) => "NCEP Ensemble Products",
+        (7, 3) => "NCEP Central Operations",
+        (7, 4) => "Environmental Modeling Center",
+        (7, 5) => "Weather Prediction Center",
+        (7, 6) => "Ocean Prediction Center",
+        (7, 7) => "Climate Prediction Center",
+        (7, 8) => "Aviation Weather Center",
+        (7, 9) => "Storm Prediction Center",
+        (7, 10) => "National Hurricane Center",
+        _ => "unknown",
+    }
+}
+
 /// Get level type name and units
 pub fn get_level_type_info(level_type: u8) -> (&'static str, &'static str) {
     match level_type {
@@ -650,12 +525,140 @@ mod tests {
 
     #[test]
     fn test_ecmwf_parameters() {
-        let param = get_parameter(98, 11).unwrap();
-        assert_eq!(param.abbreviation, "t");
-        assert_eq!(param.name, "Temperature");
+        let param = get_parameter(98, 128, 11).unwrap();
+        assert_eq!(param.abbreviation.as_ref(), "t");
+        assert_eq!(param.name.as_ref(), "Temperature");
+
+        let param = get_parameter(98, 128, 131).unwrap();
+        assert_eq!(param.abbreviation.as_ref(), "u");
+    }
+
+    #[test]
+    fn test_registry_add_and_override() {
+        let mut registry = ParameterTableRegistry::with_builtins();
+
+        // Built-in entry is visible through the registry.
+        let t = registry.get(98, 128, 11).unwrap();
+        assert_eq!(t.abbreviation.as_ref(), "t");
+
+        // A custom, owned entry can be registered and read back.
+        registry.add_param(
+            60,
+            DEFAULT_TABLE_VERSION,
+            Grib1Parameter::new(200, "inhouse".to_string(), "In-house variable".to_string(), "1"),
+        );
+        let custom = registry.get(60, DEFAULT_TABLE_VERSION, 200).unwrap();
+        assert_eq!(custom.name.as_ref(), "In-house variable");
+
+        // Modifying replaces the previous entry and returns it.
+        let prev = registry
+            .modify_param(
+                60,
+                DEFAULT_TABLE_VERSION,
+                Grib1Parameter::new(200, "inhouse2".to_string(), "Updated".to_string(), "1"),
+            )
+            .unwrap();
+        assert_eq!(prev.abbreviation.as_ref(), "inhouse");
+        assert_eq!(registry.get(60, DEFAULT_TABLE_VERSION, 200).unwrap().abbreviation.as_ref(), "inhouse2");
+
+        // Unknown center/version falls back to the WMO standard table.
+        let wmo = registry.get(200, 7, 1).unwrap();
+        assert_eq!(wmo.abbreviation.as_ref(), "pres");
+    }
+
+    #[test]
+    fn test_table_version_lookup() {
+        // ECMWF local table 128.
+        let z = get_parameter(98, 128, 129).unwrap();
+        assert_eq!(z.abbreviation.as_ref(), "z");
+
+        // DWD local table 201: parameter 1 is a shortwave flux, not pressure.
+        let swdir = get_parameter(78, 201, 1).unwrap();
+        assert_eq!(swdir.name.as_ref(), "Downward shortwave radiant flux density");
+
+        // An unknown center/version falls back to the WMO standard table.
+        let wmo = get_parameter(250, 2, 11).unwrap();
+        assert_eq!(wmo.abbreviation.as_ref(), "t");
+    }
+
+    #[test]
+    fn test_cf_metadata() {
+        // Temperature carries a CF standard name and needs no unit conversion.
+        let t = get_parameter(98, 128, 130).unwrap();
+        assert_eq!(t.cf_standard_name.as_deref(), Some("air_temperature"));
+        assert_eq!(t.to_cf(300.0), 300.0);
+
+        // Total precipitation in metres is scaled to kg m-2 by water density.
+        let tp = get_parameter(98, 128, 228).unwrap();
+        assert_eq!(tp.cf_standard_name.as_deref(), Some("precipitation_amount"));
+        let conv = tp.unit_conversion.unwrap();
+        assert_eq!(conv.target_units, "kg m-2");
+        assert_eq!(tp.to_cf(0.005), 5.0);
+    }
+
+    #[test]
+    fn test_predefined_grids() {
+        // Grid 21: 5x2.5 degree northern quarter-sphere with a pole row.
+        let g = get_predefined_grid(21).unwrap();
+        assert_eq!(g.dlon, 5.0);
+        assert_eq!(g.dlat, 2.5);
+        assert_eq!(g.lon_range, (0.0, 180.0));
+        assert_eq!(g.lat_range, (0.0, 90.0));
+        assert_eq!(g.npoints, 1333);
+        assert!(g.north_pole_row);
+
+        // Grid 25: 5x5 degree, full longitude band to 355E.
+        let g = get_predefined_grid(25).unwrap();
+        assert_eq!(g.dlat, 5.0);
+        assert_eq!(g.lon_range, (0.0, 355.0));
+        assert_eq!((g.nx, g.ny), (72, 19));
+        assert_eq!(g.npoints, 1297);
+
+        // Southern grids scan north-to-south and flag the south pole.
+        let g = get_predefined_grid(26).unwrap();
+        assert_eq!(g.lat_range, (0.0, -90.0));
+        assert!(g.south_pole_row);
+
+        assert!(get_predefined_grid(0).is_none());
+    }
+
+    #[test]
+    fn test_center_names() {
+        assert_eq!(
+            get_center_name(98),
+            "European Centre for Medium-Range Weather Forecasts"
+        );
+        assert_eq!(get_center_name(7), "US National Weather Service - NCEP");
+        assert_eq!(get_center_name(200), "unknown");
+
+        assert_eq!(get_subcenter_name(7, 10), "National Hurricane Center");
+        assert_eq!(get_subcenter_name(98, 1), "unknown");
+    }
+
+    #[test]
+    fn test_valid_range_check() {
+        // Sea-ice cover is bounded to [0, 1].
+        let ci = get_parameter(98, 128, 31).unwrap();
+        assert_eq!(ci.valid_min, 0.0);
+        assert_eq!(ci.valid_max, 1.0);
+        assert!(ci.check_value(0.5));
+        assert!(!ci.check_value(1.5));
+        assert!(!ci.check_value(-0.1));
+
+        // Relative humidity allows a little slack above saturation.
+        let r = get_parameter(98, 128, 52).unwrap();
+        assert!(r.check_value(110.0));
+        assert!(!r.check_value(130.0));
+
+        // Total precipitation must be non-negative but is otherwise unbounded.
+        let tp = get_parameter(98, 128, 228).unwrap();
+        assert!(tp.check_value(0.0));
+        assert!(!tp.check_value(-1.0));
 
-        let param = get_parameter(98, 131).unwrap();
-        assert_eq!(param.abbreviation, "u");
+        // Entries without a declared range accept everything finite.
+        let z = get_parameter(98, 128, 129).unwrap();
+        assert!(z.check_value(50000.0));
+        assert!(!z.check_value(f64::NAN));
     }
 
     #[test]